@@ -1,5 +1,7 @@
-use super::fs::{FileSystem, Cmd};
+use super::fs::{FileSystem, Cmd, Path};
+use std::collections::HashSet;
 use std::io::{stdin, stdout, Read, BufRead, BufReader, BufWriter, Write};
+use std::process::Command;
 
 const HELP: &str = "
 A simple filesystem.
@@ -99,6 +101,11 @@ impl FileSystemDriver {
     }
 
     fn exec_cmd(&mut self, cmd: Cmd) {
+        if let Cmd::Rename(path) = cmd {
+            self.exec_rename(path);
+            return;
+        }
+
         match self.fs.exec_cmd(cmd.clone()) {
             Ok(ok) => {
                 if let Some(output) = ok {
@@ -113,14 +120,114 @@ impl FileSystemDriver {
                     Cmd::Move { src, dest } => {
                         writeln!(self.writer, "Cannot move {} {} - {}", src, dest, err);
                     }
+                    Cmd::Copy { src, dest, .. } => {
+                        writeln!(self.writer, "Cannot copy {} {} - {}", src, dest, err);
+                    }
                     Cmd::Create(path) => {
                         writeln!(self.writer, "Cannot create {} - {}", path, err);
                     }
+                    Cmd::ChangeDir(path) => {
+                        writeln!(self.writer, "Cannot cd {} - {}", path, err);
+                    }
+                    Cmd::Stat(path) => {
+                        writeln!(self.writer, "Cannot stat {} - {}", path, err);
+                    }
+                    Cmd::List(path) => match path {
+                        Some(path) => {
+                            let _ = writeln!(self.writer, "Cannot list {} - {}", path, err);
+                        }
+                        None => {
+                            let _ = writeln!(self.writer, "Cannot list - {}", err);
+                        }
+                    },
                     _ => {}
                 }
             }
         }
     }
+
+    /// Implements `RENAME path` mmv-style: dump the directory's sorted
+    /// entry names into a temp file, let `$EDITOR` edit it, then apply the
+    /// edited buffer as renames within that directory.
+    fn exec_rename(&mut self, path: Path) {
+        let names = match self.fs.list_entry_names(path.clone()) {
+            Ok(names) => names,
+            Err(err) => {
+                let _ = writeln!(self.writer, "Cannot rename {} - {}", path, err);
+                return;
+            }
+        };
+
+        let tmp_path = std::env::temp_dir().join(format!("rename_{}.txt", std::process::id()));
+
+        if let Err(err) = std::fs::write(&tmp_path, names.join("\n")) {
+            let _ = writeln!(self.writer, "Cannot rename {} - {}", path, err);
+            return;
+        }
+
+        let editor = std::env::var("EDITOR").unwrap_or_else(|_| "vi".to_string());
+        let status = Command::new(editor).arg(&tmp_path).status();
+
+        let edited = match status {
+            Ok(status) if status.success() => std::fs::read_to_string(&tmp_path).map_err(|err| err.to_string()),
+            Ok(_) => Err("editor exited with a non-zero status, aborting".to_string()),
+            Err(err) => Err(err.to_string()),
+        };
+
+        let _ = std::fs::remove_file(&tmp_path);
+
+        let edited = match edited {
+            Ok(edited) => edited,
+            Err(err) => {
+                let _ = writeln!(self.writer, "Cannot rename {} - {}", path, err);
+                return;
+            }
+        };
+
+        let new_names: Vec<String> = edited.lines().map(|s| s.to_string()).collect();
+
+        if new_names.len() != names.len() {
+            let _ = writeln!(
+                self.writer,
+                "Cannot rename {} - Files have been added or removed during editing",
+                path
+            );
+            return;
+        }
+
+        for new_name in new_names.iter() {
+            if new_name.is_empty() || new_name.contains('/') {
+                let _ = writeln!(
+                    self.writer,
+                    "Cannot rename {} - invalid name {:?} in edited buffer",
+                    path, new_name
+                );
+                return;
+            }
+        }
+
+        let mut seen = HashSet::new();
+        for new_name in new_names.iter() {
+            if !seen.insert(new_name) {
+                let _ = writeln!(
+                    self.writer,
+                    "Cannot rename {} - duplicate name {} in edited buffer",
+                    path, new_name
+                );
+                return;
+            }
+        }
+
+        let renames: Vec<(String, String)> = names
+            .into_iter()
+            .zip(new_names)
+            .filter(|(old, new)| old != new)
+            .collect();
+
+        if let Err(err) = self.fs.rename_many(path.clone(), renames) {
+            let _ = writeln!(self.writer, "Cannot rename {} - {}", path, err);
+        }
+    }
 }
 
 #[cfg(test)]
@@ -1,15 +1,17 @@
+use super::path::{has_wildcard, matches};
 use std::collections::HashMap;
 use std::fmt::Display;
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub enum FileSystemError {
     PathDoesNotExist(Path),
     BadPath,
-    CmdDoesNotExist,
+    CmdDoesNotExist(String),
     InvalidCmdArgs,
     InvalidProgramArgs,
     InternalError,
     FileAlreadyExists,
+    DirectoryNotEmpty(Path),
 }
 
 impl Display for FileSystemError {
@@ -18,8 +20,30 @@ impl Display for FileSystemError {
             Self::PathDoesNotExist(path) => {
                 write!(f, "{} does not exist", String::from(path.clone()))
             }
-            _ => {
-                todo!()
+            Self::BadPath => {
+                write!(f, "invalid path")
+            }
+            Self::CmdDoesNotExist(cmd) => {
+                write!(f, "command not found: {}", cmd)
+            }
+            Self::DirectoryNotEmpty(path) => {
+                write!(
+                    f,
+                    "{} is not empty, use --recursive to copy non-empty directories",
+                    String::from(path.clone())
+                )
+            }
+            Self::InvalidCmdArgs => {
+                write!(f, "invalid command arguments")
+            }
+            Self::InvalidProgramArgs => {
+                write!(f, "invalid program arguments")
+            }
+            Self::InternalError => {
+                write!(f, "internal error")
+            }
+            Self::FileAlreadyExists => {
+                write!(f, "file already exists")
             }
         }
     }
@@ -40,6 +64,26 @@ impl Path {
     fn push_file(&mut self, file: String) {
         self.file_names.push(file);
     }
+
+    /// Resolves `self` as a path relative to `base`, folding `.` and `..`
+    /// segments. Popping past the root is a `BadPath` error.
+    fn normalize(&self, base: &Path) -> Result<Path, FileSystemError> {
+        let mut file_names = base.file_names.clone();
+
+        for file_name in self.file_names.iter() {
+            match file_name.as_str() {
+                "." => {}
+                ".." => {
+                    if file_names.pop().is_none() {
+                        return Err(FileSystemError::BadPath);
+                    }
+                }
+                _ => file_names.push(file_name.clone()),
+            }
+        }
+
+        Ok(Path { file_names })
+    }
 }
 
 impl Display for Path {
@@ -85,12 +129,20 @@ impl TryFrom<&str> for Path {
 #[derive(Clone)]
 pub enum Cmd {
     Move {
-        src: Path, 
+        src: Path,
         dest: Path
     },
+    Copy {
+        src: Path,
+        dest: Path,
+        recursive: bool,
+    },
     Create(Path),
     Delete(Path),
-    List,
+    List(Option<Path>),
+    ChangeDir(Path),
+    Stat(Path),
+    Rename(Path),
 }
 
 impl TryFrom<&str> for Cmd {
@@ -115,6 +167,21 @@ impl TryFrom<&str> for Cmd {
                     }
                 )
             }
+            "COPY" => {
+                let recursive = match args.len() {
+                    3 => false,
+                    4 if args[3] == "--recursive" => true,
+                    _ => return Err(FileSystemError::InvalidCmdArgs),
+                };
+
+                Ok(
+                    Cmd::Copy {
+                        src: Path::try_from(args[1].as_str())?,
+                        dest: Path::try_from(args[2].as_str())?,
+                        recursive,
+                    }
+                )
+            }
             "CREATE" => {
                 if args.len() != 2 {
                     return Err(FileSystemError::InvalidCmdArgs);
@@ -134,30 +201,76 @@ impl TryFrom<&str> for Cmd {
                 Ok(Cmd::Delete(path))
             }
             "LIST" => {
-                if args.len() != 1 {
+                match args.len() {
+                    1 => Ok(Cmd::List(None)),
+                    2 => Ok(Cmd::List(Some(Path::try_from(args[1].as_str())?))),
+                    _ => Err(FileSystemError::InvalidCmdArgs),
+                }
+            }
+            "CD" => {
+                if args.len() != 2 {
                     return Err(FileSystemError::InvalidCmdArgs);
                 }
 
-                Ok(Cmd::List)
+                Ok(Cmd::ChangeDir(Path::try_from(args[1].as_str())?))
             }
-            _ => Err(FileSystemError::CmdDoesNotExist),
+            "STAT" => {
+                if args.len() != 2 {
+                    return Err(FileSystemError::InvalidCmdArgs);
+                }
+
+                Ok(Cmd::Stat(Path::try_from(args[1].as_str())?))
+            }
+            "RENAME" => {
+                if args.len() != 2 {
+                    return Err(FileSystemError::InvalidCmdArgs);
+                }
+
+                Ok(Cmd::Rename(Path::try_from(args[1].as_str())?))
+            }
+            cmd => Err(FileSystemError::CmdDoesNotExist(cmd.to_string())),
         }
     }
 }
 
+/// Metadata tracked for every `Dir` that isn't part of its structural
+/// identity (path, children) but is useful for introspection via `STAT`.
+#[derive(Debug, Clone, PartialEq)]
+struct DirMeta {
+    /// Monotonically increasing order in which this `Dir` was created.
+    seq: usize,
+}
+
+#[derive(Clone)]
 struct Dir {
     path: Path,
     entries: HashMap<String, Dir>,
+    meta: DirMeta,
 }
 
 impl Dir {
-    fn new(path: Path) -> Self {
+    fn new(path: Path, seq: usize) -> Self {
         Self {
             path,
-            entries: HashMap::new()
+            entries: HashMap::new(),
+            meta: DirMeta { seq },
         }
     }
 
+    /// Number of direct children.
+    fn child_count(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Total number of directories nested anywhere beneath this one.
+    fn descendant_count(&self) -> usize {
+        let mut count = 0;
+
+        FileSystem::traverse_dir(self, 0, &mut |_, _| count += 1);
+
+        count
+    }
+
     fn create_dir(&mut self, name: String, dir: Dir) -> Result<(), FileSystemError> {
         if self.entries.get(&name).is_some() {
             return Err(FileSystemError::FileAlreadyExists);
@@ -180,56 +293,211 @@ impl Dir {
 
         Ok(dir)
     }
+
+    /// Deep clones this directory and everything beneath it, rewriting
+    /// every path in the subtree to live under `new_path` instead of
+    /// wherever the original was rooted, and handing each cloned `Dir` a
+    /// fresh creation sequence number from `next_seq`.
+    fn clone_subtree(&self, new_path: Path, next_seq: &mut impl FnMut() -> usize) -> Dir {
+        let mut cloned = self.clone();
+        cloned.reroot(new_path, next_seq);
+        cloned
+    }
+
+    fn reroot(&mut self, new_path: Path, next_seq: &mut impl FnMut() -> usize) {
+        for (name, child) in self.entries.iter_mut() {
+            let mut child_path = new_path.clone();
+            child_path.push_file(name.clone());
+            child.reroot(child_path, next_seq);
+        }
+
+        self.path = new_path;
+        self.meta.seq = next_seq();
+    }
 }
 
 pub struct FileSystem {
     root: Dir,
+    cwd: Path,
+    next_seq: usize,
 }
 
 impl FileSystem {
     pub fn new() -> Self {
         Self {
-            root: Dir::new(Path::new()),
+            root: Dir::new(Path::new(), 0),
+            cwd: Path::new(),
+            next_seq: 1,
         }
     }
 
+    fn alloc_seq(&mut self) -> usize {
+        let seq = self.next_seq;
+        self.next_seq += 1;
+        seq
+    }
+
     /// Attempts to execute a command.
+    ///
+    /// Every path carried by `cmd` is normalized against `cwd` before
+    /// traversal, so relative `.`/`..` segments resolve the same way they
+    /// would from a shell.
     pub fn exec_cmd(&mut self, cmd: Cmd) -> Result<Option<String>, FileSystemError> {
         match cmd {
-            Cmd::List => {
+            Cmd::List(path) => {
+                let path = path.unwrap_or_else(Path::new).normalize(&self.cwd)?;
                 let mut output = String::new();
 
-                self.traverse_dir(&self.root, 0, &mut |entry, depth| {
-                    for _ in 0..depth {
-                        output.push_str("  ");
-                    }
+                if path.file_names.last().is_some_and(|name| has_wildcard(name)) {
+                    let mut parent = path.clone();
+                    let pattern = parent.file_names.pop().unwrap();
+
+                    self.access_dir(parent, |dir| {
+                        let mut names: Vec<&String> = dir
+                            .entries
+                            .keys()
+                            .filter(|name| matches(&pattern, name))
+                            .collect();
+                        names.sort();
+
+                        for name in names {
+                            output.push_str(name);
+                            output.push('\n');
+
+                            Self::traverse_dir(&dir.entries[name], 1, &mut |entry, depth| {
+                                for _ in 0..depth {
+                                    output.push_str("  ");
+                                }
+
+                                output.push_str(entry);
+                                output.push('\n');
+                            });
+                        }
+
+                        Ok(())
+                    })?;
+                } else {
+                    self.access_dir(path, |dir| {
+                        Self::traverse_dir(dir, 0, &mut |entry, depth| {
+                            for _ in 0..depth {
+                                output.push_str("  ");
+                            }
 
-                    output.push_str(entry);
+                            output.push_str(entry);
 
-                    output.push('\n');
-                });
+                            output.push('\n');
+                        });
+
+                        Ok(())
+                    })?;
+                }
 
                 output.pop();
 
                 Ok(Some(output))
             }
-            Cmd::Move { mut src, dest } => {
-                let move_file_name = src.file_names.pop().unwrap();
+            Cmd::Move { src, dest } => {
+                let mut src = src.normalize(&self.cwd)?;
+                let dest = dest.normalize(&self.cwd)?;
+                let move_file_name = src.file_names.pop().ok_or(FileSystemError::BadPath)?;
+
+                if has_wildcard(&move_file_name) {
+                    let matched: Vec<String> = self.access_dir(src.clone(), |dir| {
+                        let matched: Vec<String> = dir
+                            .entries
+                            .keys()
+                            .filter(|name| matches(&move_file_name, name))
+                            .cloned()
+                            .collect();
+
+                        if matched.is_empty() {
+                            let mut bad_path = dir.path.clone();
+                            bad_path.push_file(move_file_name.clone());
+
+                            return Err(FileSystemError::PathDoesNotExist(bad_path));
+                        }
+
+                        Ok(matched)
+                    })?;
+
+                    self.access_dir(dest.clone(), |dir| {
+                        if matched.iter().any(|name| dir.entries.contains_key(name)) {
+                            return Err(FileSystemError::FileAlreadyExists);
+                        }
+
+                        Ok(())
+                    })?;
+
+                    let moved_dirs = self.access_dir(src, |dir| {
+                        let mut out = Vec::new();
+                        for name in matched {
+                            let dir = dir.delete(&name)?;
+                            out.push((name, dir));
+                        }
 
-                let move_dir: Dir = 
-                    self.access_dir(src, |dir| {
-                        Ok(dir.delete(&move_file_name)?)
+                        Ok(out)
                     })?;
 
-                self.access_dir(dest, |dir| {
-                    dir.create_dir(move_file_name, move_dir)?;
+                    self.access_dir(dest, |dir| {
+                        for (name, moved_dir) in moved_dirs {
+                            dir.create_dir(name, moved_dir)?;
+                        }
+
+                        Ok(None)
+                    })
+                } else {
+                    let move_dir: Dir =
+                        self.access_dir(src, |dir| {
+                            Ok(dir.delete(&move_file_name)?)
+                        })?;
+
+                    self.access_dir(dest, |dir| {
+                        dir.create_dir(move_file_name, move_dir)?;
+
+                        Ok(None)
+                    })
+                }
+            }
+            Cmd::Copy { src, dest, recursive } => {
+                let src = src.normalize(&self.cwd)?;
+                let dest = dest.normalize(&self.cwd)?;
+                let name = src.file_names.last().cloned().ok_or(FileSystemError::BadPath)?;
+
+                let snapshot = self.access_dir(src, |dir| {
+                    if !recursive && !dir.entries.is_empty() {
+                        return Err(FileSystemError::DirectoryNotEmpty(dir.path.clone()));
+                    }
+
+                    Ok(dir.clone())
+                })?;
+
+                let mut seq = self.next_seq;
+                let result = self.access_dir(dest, |dir| {
+                    let mut new_path = dir.path.clone();
+                    new_path.push_file(name.clone());
+
+                    let cloned = snapshot.clone_subtree(new_path, &mut || {
+                        let s = seq;
+                        seq += 1;
+                        s
+                    });
+
+                    dir.create_dir(name, cloned)?;
 
                     Ok(None)
-                })
+                });
+
+                if result.is_ok() {
+                    self.next_seq = seq;
+                }
+
+                result
             }
-            Cmd::Create(mut path) => {
-                let new_dir = Dir::new(path.clone());
-                let new_file = path.file_names.pop().unwrap();
+            Cmd::Create(path) => {
+                let mut path = path.normalize(&self.cwd)?;
+                let full_path = path.clone();
+                let new_file = path.file_names.pop().ok_or(FileSystemError::BadPath)?;
+                let new_dir = Dir::new(full_path, self.alloc_seq());
 
                 self.access_dir(path, |dir| {
                     dir.create_dir(new_file, new_dir)?;
@@ -237,18 +505,117 @@ impl FileSystem {
                     Ok(None)
                 })
             }
-            Cmd::Delete(mut path) => {
-                let file_name = path.file_names.pop().unwrap();
+            Cmd::Delete(path) => {
+                let mut path = path.normalize(&self.cwd)?;
+                let file_name = path.file_names.pop().ok_or(FileSystemError::BadPath)?;
 
                 self.access_dir(path, |dir| {
-                    dir.delete(&file_name)?;
+                    if has_wildcard(&file_name) {
+                        let matched: Vec<String> = dir
+                            .entries
+                            .keys()
+                            .filter(|name| matches(&file_name, name))
+                            .cloned()
+                            .collect();
+
+                        if matched.is_empty() {
+                            let mut bad_path = dir.path.clone();
+                            bad_path.push_file(file_name.clone());
+
+                            return Err(FileSystemError::PathDoesNotExist(bad_path));
+                        }
+
+                        for name in matched {
+                            dir.delete(&name)?;
+                        }
+                    } else {
+                        dir.delete(&file_name)?;
+                    }
 
                     Ok(None)
                 })
             }
+            Cmd::ChangeDir(path) => {
+                let path = path.normalize(&self.cwd)?;
+
+                self.access_dir(path.clone(), |_dir| Ok(()))?;
+                self.cwd = path;
+
+                Ok(None)
+            }
+            Cmd::Stat(path) => {
+                let path = path.normalize(&self.cwd)?;
+
+                self.access_dir(path.clone(), |dir| {
+                    let report = format!(
+                        "{}\nchildren: {}\ndescendants: {}\ncreated: {}",
+                        String::from(path.clone()),
+                        dir.child_count(),
+                        dir.descendant_count(),
+                        dir.meta.seq,
+                    );
+
+                    Ok(Some(report))
+                })
+            }
+            // RENAME needs to shell out to $EDITOR, which FileSystem has no
+            // business knowing about; FileSystemDriver handles it directly
+            // via list_entry_names/rename_many instead of exec_cmd.
+            Cmd::Rename(_) => unreachable!("Cmd::Rename is handled by FileSystemDriver"),
         }
     }
 
+    /// Lists the names of the direct children of `path`, sorted, for a
+    /// caller (the driver) that needs to present them for editing.
+    pub fn list_entry_names(&mut self, path: Path) -> Result<Vec<String>, FileSystemError> {
+        let path = path.normalize(&self.cwd)?;
+
+        self.access_dir(path, |dir| {
+            let mut names: Vec<String> = dir.entries.keys().cloned().collect();
+            names.sort();
+
+            Ok(names)
+        })
+    }
+
+    /// Atomically renames entries within the directory at `path`.
+    ///
+    /// Every removal is staged into a temporary map before any entry is
+    /// reinserted, so a cycle like `a -> b, b -> a` doesn't clobber `b`
+    /// with the about-to-be-renamed `a`.
+    pub fn rename_many(
+        &mut self,
+        path: Path,
+        renames: Vec<(String, String)>,
+    ) -> Result<(), FileSystemError> {
+        let path = path.normalize(&self.cwd)?;
+
+        self.access_dir(path, |dir| {
+            let mut checked = std::collections::HashSet::new();
+            for (old_name, _) in renames.iter() {
+                if !dir.entries.contains_key(old_name) || !checked.insert(old_name) {
+                    let mut bad_path = dir.path.clone();
+                    bad_path.push_file(old_name.clone());
+
+                    return Err(FileSystemError::PathDoesNotExist(bad_path));
+                }
+            }
+
+            let mut staged = HashMap::new();
+
+            for (old_name, _) in renames.iter() {
+                staged.insert(old_name.clone(), dir.delete(old_name)?);
+            }
+
+            for (old_name, new_name) in renames {
+                let entry = staged.remove(&old_name).unwrap();
+                dir.entries.insert(new_name, entry);
+            }
+
+            Ok(())
+        })
+    }
+
     /// Attempts to follow a path in the filesystem.
     ///
     /// May return PathDoesNotExist if path cannot be followed.
@@ -280,11 +647,11 @@ impl FileSystem {
     ///
     /// The provided callback is called for every directory found
     /// and passes in the "depth" of the folder and its file name as args.
-    fn traverse_dir(&self, dir: &Dir, depth: usize, cb: &mut impl FnMut(&str, usize)) {
+    fn traverse_dir(dir: &Dir, depth: usize, cb: &mut impl FnMut(&str, usize)) {
         for (entry, dir) in dir.entries.iter() {
             cb(entry, depth);
 
-            self.traverse_dir(dir, depth + 1, cb);
+            Self::traverse_dir(dir, depth + 1, cb);
         }
     }
 }
@@ -296,7 +663,7 @@ mod tests {
     #[test]
     fn list_empty_dir() {
         let mut fs = FileSystem::new();
-        let result = fs.exec_cmd(Cmd::List).unwrap();
+        let result = fs.exec_cmd(Cmd::List(None)).unwrap();
         let expect = Some("".into());
 
         assert_eq!(result, expect);
@@ -343,4 +710,199 @@ mod tests {
 
         assert!(dir_b.entries.len() == 0);
     }
+
+    #[test]
+    fn copy_directory_recursive() {
+        let mut fs = FileSystem::new();
+        let path_a = Path::try_from("a").unwrap();
+        let path_b = Path::try_from("a/b").unwrap();
+        let path_c = Path::try_from("c").unwrap();
+
+        fs.exec_cmd(Cmd::Create(path_a.clone())).unwrap();
+        fs.exec_cmd(Cmd::Create(path_b)).unwrap();
+        fs.exec_cmd(Cmd::Create(path_c.clone())).unwrap();
+
+        fs.exec_cmd(Cmd::Copy { src: path_a.clone(), dest: path_c, recursive: true }).unwrap();
+
+        // the original subtree is untouched
+        assert!(fs.root.entries.get("a".into()).unwrap().entries.len() == 1);
+
+        let copied_a = fs
+            .root
+            .entries
+            .get("c".into())
+            .unwrap()
+            .entries
+            .get("a".into())
+            .unwrap();
+
+        assert!(copied_a.entries.get("b".into()).is_some());
+    }
+
+    #[test]
+    fn copy_non_empty_dir_without_recursive_fails() {
+        let mut fs = FileSystem::new();
+        let path_a = Path::try_from("a").unwrap();
+        let path_b = Path::try_from("a/b").unwrap();
+        let path_c = Path::try_from("c").unwrap();
+
+        fs.exec_cmd(Cmd::Create(path_a.clone())).unwrap();
+        fs.exec_cmd(Cmd::Create(path_b)).unwrap();
+        fs.exec_cmd(Cmd::Create(path_c.clone())).unwrap();
+
+        let result = fs.exec_cmd(Cmd::Copy { src: path_a, dest: path_c, recursive: false });
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn delete_wildcard_matches_all_entries() {
+        let mut fs = FileSystem::new();
+
+        fs.exec_cmd(Cmd::Create(Path::try_from("a.tmp").unwrap())).unwrap();
+        fs.exec_cmd(Cmd::Create(Path::try_from("b.tmp").unwrap())).unwrap();
+        fs.exec_cmd(Cmd::Create(Path::try_from("c.log").unwrap())).unwrap();
+
+        fs.exec_cmd(Cmd::Delete(Path::try_from("*.tmp").unwrap())).unwrap();
+
+        assert!(fs.root.entries.len() == 1);
+        assert!(fs.root.entries.get("c.log".into()).is_some());
+    }
+
+    #[test]
+    fn list_wildcard_filters_output() {
+        let mut fs = FileSystem::new();
+
+        fs.exec_cmd(Cmd::Create(Path::try_from("src").unwrap())).unwrap();
+        fs.exec_cmd(Cmd::Create(Path::try_from("src/a").unwrap())).unwrap();
+        fs.exec_cmd(Cmd::Create(Path::try_from("src/b").unwrap())).unwrap();
+
+        let result = fs.exec_cmd(Cmd::List(Some(Path::try_from("src/*").unwrap()))).unwrap();
+
+        assert_eq!(result, Some("a\nb".to_string()));
+    }
+
+    #[test]
+    fn cd_tracks_working_directory() {
+        let mut fs = FileSystem::new();
+
+        fs.exec_cmd(Cmd::Create(Path::try_from("a").unwrap())).unwrap();
+        fs.exec_cmd(Cmd::Create(Path::try_from("a/b").unwrap())).unwrap();
+
+        fs.exec_cmd(Cmd::ChangeDir(Path::try_from("a").unwrap())).unwrap();
+        fs.exec_cmd(Cmd::Create(Path::try_from("c").unwrap())).unwrap();
+
+        let dir_a = fs.root.entries.get("a".into()).unwrap();
+
+        assert!(dir_a.entries.get("b".into()).is_some());
+        assert!(dir_a.entries.get("c".into()).is_some());
+    }
+
+    #[test]
+    fn cd_resolves_dot_dot() {
+        let mut fs = FileSystem::new();
+
+        fs.exec_cmd(Cmd::Create(Path::try_from("a").unwrap())).unwrap();
+        fs.exec_cmd(Cmd::Create(Path::try_from("a/b").unwrap())).unwrap();
+
+        fs.exec_cmd(Cmd::ChangeDir(Path::try_from("a/b").unwrap())).unwrap();
+        fs.exec_cmd(Cmd::ChangeDir(Path::try_from("..").unwrap())).unwrap();
+        fs.exec_cmd(Cmd::Create(Path::try_from("c").unwrap())).unwrap();
+
+        let dir_a = fs.root.entries.get("a".into()).unwrap();
+
+        assert!(dir_a.entries.get("c".into()).is_some());
+    }
+
+    #[test]
+    fn cd_past_root_is_bad_path() {
+        let mut fs = FileSystem::new();
+
+        let result = fs.exec_cmd(Cmd::ChangeDir(Path::try_from("..").unwrap()));
+
+        assert_eq!(result, Err(FileSystemError::BadPath));
+    }
+
+    #[test]
+    fn stat_reports_children_and_descendants() {
+        let mut fs = FileSystem::new();
+        let path_a = Path::try_from("a").unwrap();
+
+        fs.exec_cmd(Cmd::Create(path_a.clone())).unwrap();
+        fs.exec_cmd(Cmd::Create(Path::try_from("a/b").unwrap())).unwrap();
+        fs.exec_cmd(Cmd::Create(Path::try_from("a/b/c").unwrap())).unwrap();
+
+        let result = fs.exec_cmd(Cmd::Stat(path_a)).unwrap().unwrap();
+
+        assert!(result.contains("children: 1"));
+        assert!(result.contains("descendants: 2"));
+    }
+
+    #[test]
+    fn stat_seq_increases_with_creation_order() {
+        let mut fs = FileSystem::new();
+
+        fs.exec_cmd(Cmd::Create(Path::try_from("a").unwrap())).unwrap();
+        fs.exec_cmd(Cmd::Create(Path::try_from("b").unwrap())).unwrap();
+
+        let stat_a = fs.exec_cmd(Cmd::Stat(Path::try_from("a").unwrap())).unwrap().unwrap();
+        let stat_b = fs.exec_cmd(Cmd::Stat(Path::try_from("b").unwrap())).unwrap().unwrap();
+
+        assert!(stat_a.contains("created: 1"));
+        assert!(stat_b.contains("created: 2"));
+    }
+
+    #[test]
+    fn list_entry_names_returns_sorted_children() {
+        let mut fs = FileSystem::new();
+        let path_a = Path::try_from("a").unwrap();
+
+        fs.exec_cmd(Cmd::Create(path_a.clone())).unwrap();
+        fs.exec_cmd(Cmd::Create(Path::try_from("a/c").unwrap())).unwrap();
+        fs.exec_cmd(Cmd::Create(Path::try_from("a/b").unwrap())).unwrap();
+
+        let names = fs.list_entry_names(path_a).unwrap();
+
+        assert_eq!(names, vec!["b".to_string(), "c".to_string()]);
+    }
+
+    #[test]
+    fn rename_many_applies_renames_within_dir() {
+        let mut fs = FileSystem::new();
+        let path_a = Path::try_from("a").unwrap();
+
+        fs.exec_cmd(Cmd::Create(path_a.clone())).unwrap();
+        fs.exec_cmd(Cmd::Create(Path::try_from("a/b").unwrap())).unwrap();
+
+        fs.rename_many(path_a.clone(), vec![("b".to_string(), "renamed_b".to_string())])
+            .unwrap();
+
+        let names = fs.list_entry_names(path_a).unwrap();
+
+        assert_eq!(names, vec!["renamed_b".to_string()]);
+    }
+
+    #[test]
+    fn rename_many_swaps_entries_in_a_cycle() {
+        let mut fs = FileSystem::new();
+        let path_a = Path::try_from("a").unwrap();
+
+        fs.exec_cmd(Cmd::Create(path_a.clone())).unwrap();
+        fs.exec_cmd(Cmd::Create(Path::try_from("a/b").unwrap())).unwrap();
+        fs.exec_cmd(Cmd::Create(Path::try_from("a/b/inner").unwrap())).unwrap();
+        fs.exec_cmd(Cmd::Create(Path::try_from("a/c").unwrap())).unwrap();
+
+        fs.rename_many(
+            path_a.clone(),
+            vec![
+                ("b".to_string(), "c".to_string()),
+                ("c".to_string(), "b".to_string()),
+            ],
+        )
+        .unwrap();
+
+        let inner = fs.list_entry_names(Path::try_from("a/c").unwrap()).unwrap();
+
+        assert_eq!(inner, vec!["inner".to_string()]);
+    }
 }
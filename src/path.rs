@@ -65,3 +65,73 @@ impl TryFrom<&str> for Path {
         Ok(Self { file_names })
     }
 }
+
+/// Returns true if `name` contains any characters that make it a wildcard
+/// pattern (`*` or `?`).
+pub fn has_wildcard(name: &str) -> bool {
+    name.contains('*') || name.contains('?')
+}
+
+/// Shell-style glob match: `*` matches any run of characters (including
+/// none), `?` matches exactly one character, everything else is literal.
+///
+/// Uses the standard two-pointer backtracking approach: advance both
+/// cursors on a literal/`?` match, and on a `*` remember where we are so
+/// that a later literal mismatch can fall back and try consuming one more
+/// character with the star.
+pub fn matches(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+
+    let (mut pi, mut ni) = (0, 0);
+    let mut star: Option<usize> = None;
+    let mut star_match = 0;
+
+    while ni < name.len() {
+        if pi < pattern.len() && (pattern[pi] == '?' || pattern[pi] == name[ni]) {
+            pi += 1;
+            ni += 1;
+        } else if pi < pattern.len() && pattern[pi] == '*' {
+            star = Some(pi);
+            star_match = ni;
+            pi += 1;
+        } else if let Some(star_pi) = star {
+            pi = star_pi + 1;
+            star_match += 1;
+            ni = star_match;
+        } else {
+            return false;
+        }
+    }
+
+    while pi < pattern.len() && pattern[pi] == '*' {
+        pi += 1;
+    }
+
+    pi == pattern.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn literal_pattern_behaves_like_equality() {
+        assert!(matches("foo.txt", "foo.txt"));
+        assert!(!matches("foo.txt", "bar.txt"));
+    }
+
+    #[test]
+    fn star_matches_any_run_including_empty() {
+        assert!(matches("*.tmp", "a.tmp"));
+        assert!(matches("*.tmp", ".tmp"));
+        assert!(matches("a*", "a"));
+    }
+
+    #[test]
+    fn question_mark_matches_one_char() {
+        assert!(matches("a?c", "abc"));
+        assert!(!matches("a?c", "ac"));
+        assert!(!matches("a?c", "abbc"));
+    }
+}
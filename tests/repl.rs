@@ -0,0 +1,39 @@
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+/// Drives the compiled binary over stdin the way a real user would in an
+/// interactive session, and returns its normalized stdout. This exercises
+/// `run_repl` end-to-end (argument parsing, flushing, error formatting),
+/// which the file-mode `test_example` fixtures never cover.
+fn run_session(script: &str) -> String {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_endpoint_challenge"))
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(script.as_bytes())
+        .unwrap();
+
+    let output = child.wait_with_output().unwrap();
+
+    String::from_utf8(output.stdout).unwrap().replace("\r\n", "\n")
+}
+
+#[test]
+fn repl_echoes_commands_and_prints_output() {
+    let output = run_session("CREATE a\nLIST\n");
+
+    assert_eq!(output, "CREATE a\nLIST\na\n");
+}
+
+#[test]
+fn repl_reports_invalid_commands_without_halting() {
+    let output = run_session("CREATE a\nNOPE\nLIST\n");
+
+    assert_eq!(output, "CREATE a\ncommand not found: NOPE\nLIST\na\n");
+}